@@ -21,9 +21,19 @@ use barter_integration::{
     protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
     ExchangeStream,
 };
-use futures::{SinkExt, Stream, StreamExt};
-use tokio::sync::mpsc;
-use tracing::{debug, error};
+use futures::{future::BoxFuture, FutureExt, SinkExt, Stream, StreamExt};
+use rand::Rng;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, error, warn};
 
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
@@ -91,10 +101,125 @@ where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
 
+/// Pluggable byte/message transport backing a [`MarketStream`].
+///
+/// [`MarketStream::init`] is conceptually protocol-agnostic; `Transport` abstracts the concrete
+/// connection so the same generic `init` can be driven by a WebSocket, a REST long-poll, or a raw
+/// datagram socket. A `connect` establishes the session and hands back the split [`Sink`](Self::Sink)
+/// / [`Stream`](Self::Stream) halves together with the subscription `Map` required to build the
+/// [`ExchangeTransformer`].
+///
+/// Each exchange selects its transport via the [`Connector::Transport`] associated type, which
+/// [`MarketStream::init`] drives off directly; [`WebSocketTransport`] is the default. The generic
+/// `init` and [`ReconnectingStream`] accept only a `Connector::Transport` whose `Sink` is
+/// [`WsSink`], whose `Stream` is [`WsStream`] and whose `Map` is the exchange
+/// [`Subscriber`]'s subscription map; a transport with other halves (eg/ a REST long-poll or a
+/// raw-datagram backend) is not accepted by them.
+/// Backends without an application-level sink or pings override
+/// [`distribute_messages`](Self::distribute_messages) / [`schedule_pings`](Self::schedule_pings)
+/// with no-ops.
+#[async_trait]
+pub trait Transport<Exchange, Kind>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    /// Outbound half used to transmit subscription/ping payloads back to the exchange.
+    type Sink: Send;
+
+    /// Inbound half of raw exchange messages consumed by the [`ExchangeTransformer`].
+    type Stream: Send;
+
+    /// Subscription map handed to [`ExchangeTransformer::new`] to translate exchange messages.
+    type Map: Send;
+
+    /// Connect and subscribe, returning the split sink/stream halves and the subscription `Map`.
+    async fn connect(
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Result<(Self::Sink, Self::Stream, Self::Map), DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// Spawn the task forwarding transformer output [`WsMessage`]s to the exchange via the sink,
+    /// returning its [`JoinHandle`] so the reconnection layer can abort it on teardown.
+    ///
+    /// Transports that transmit synchronously (or have no outbound channel) override this with a
+    /// no-op.
+    fn distribute_messages(
+        exchange: ExchangeId,
+        sink: Self::Sink,
+        rx: OutboundRx<WsMessage>,
+    ) -> JoinHandle<()>;
+
+    /// Spawn the optional application-level ping task, returning its [`JoinHandle`] (or `None` when
+    /// the backend relies solely on protocol-level keep-alives) so it can be aborted on teardown.
+    fn schedule_pings(
+        _exchange: ExchangeId,
+        _sink_tx: OutboundTx<WsMessage>,
+        _ping_interval: PingInterval,
+    ) -> Option<JoinHandle<()>> {
+        None
+    }
+}
+
+/// Tungstenite WebSocket [`Transport`] backing [`ExchangeWsStream`].
+#[derive(Debug, Clone, Copy)]
+pub struct WebSocketTransport;
+
+#[async_trait]
+impl<Exchange, Kind> Transport<Exchange, Kind> for WebSocketTransport
+where
+    Exchange: Connector + Send + Sync,
+    Kind: SubKind + Send + Sync,
+{
+    type Sink = WsSink;
+    type Stream = WsStream;
+    type Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap;
+
+    async fn connect(
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Result<(Self::Sink, Self::Stream, Self::Map), DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Connect & subscribe, then split the WebSocket into WsStream & WsSink components
+        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (ws_sink, ws_stream) = websocket.split();
+        Ok((ws_sink, ws_stream, map))
+    }
+
+    fn distribute_messages(
+        exchange: ExchangeId,
+        sink: Self::Sink,
+        rx: OutboundRx<WsMessage>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(distribute_messages_to_exchange(exchange, sink, rx))
+    }
+
+    fn schedule_pings(
+        exchange: ExchangeId,
+        sink_tx: OutboundTx<WsMessage>,
+        ping_interval: PingInterval,
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(schedule_pings_to_exchange(
+            exchange,
+            sink_tx,
+            ping_interval,
+        )))
+    }
+}
+
 #[async_trait]
 impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind> for ExchangeWsStream<Transformer>
 where
     Exchange: Connector + Send + Sync,
+    Exchange::Transport: Transport<
+        Exchange,
+        Kind,
+        Sink = WsSink,
+        Stream = WsStream,
+        Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap,
+    >,
     Kind: SubKind + Send + Sync,
     Transformer: ExchangeTransformer<Exchange, Kind> + Send,
     Kind::Event: Send,
@@ -103,63 +228,647 @@ where
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        // One-shot stream: the spawned tasks live for the connection's lifetime and tear down via
+        // channel close on disconnect, so detach the guard rather than aborting.
+        let (stream, guard, _activity) =
+            connect_and_spawn::<Exchange, Kind, Transformer>(subscriptions).await?;
+        guard.detach();
+        Ok(stream)
+    }
+}
 
-        // Split WebSocket into WsStream & WsSink components
-        let (ws_sink, ws_stream) = websocket.split();
+/// Aborts the per-connection forwarding/ping tasks when dropped, so the [`ReconnectingStream`]
+/// reconnect loop cannot leak a `distribute` + `ping` task per reconnect on rapid flapping (where a
+/// clean `None`/non-sink-fault teardown leaves the sink writable and channel-close alone would
+/// never stop the old tasks).
+#[derive(Debug)]
+pub struct TaskGuard {
+    handles: Vec<JoinHandle<()>>,
+    armed: bool,
+}
+
+impl TaskGuard {
+    fn new(handles: Vec<JoinHandle<()>>) -> Self {
+        Self {
+            handles,
+            armed: true,
+        }
+    }
+
+    /// Detach the tasks so they keep running after the guard drops. Used by the one-shot
+    /// [`MarketStream::init`] path, which has no reconnect loop to outlive them.
+    fn detach(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            for handle in &self.handles {
+                handle.abort();
+            }
+        }
+    }
+}
 
-        // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
-        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
-        tokio::spawn(distribute_messages_to_exchange(
+/// Connect & subscribe via the exchange's configured [`Transport`] (WebSocket by default), spawning
+/// the forwarding / ping tasks and returning them in a [`TaskGuard`] alongside the stream.
+async fn connect_and_spawn<Exchange, Kind, Transformer>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Result<(ExchangeWsStream<Transformer>, TaskGuard, ActivityTracker), DataError>
+where
+    Exchange: Connector + Send + Sync,
+    Exchange::Transport: Transport<
+        Exchange,
+        Kind,
+        Sink = WsSink,
+        Stream = WsStream,
+        Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap,
+    >,
+    Kind: SubKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    type Tp<E> = <E as Connector>::Transport;
+
+    let (ws_sink, ws_stream, map) =
+        <Tp<Exchange> as Transport<Exchange, Kind>>::connect(subscriptions).await?;
+
+    // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange, using the
+    // exchange's configured backpressure policy for the forwarding channel
+    let (ws_sink_tx, ws_sink_rx) = Exchange::backpressure().channel::<WsMessage>();
+    let mut handles = Vec::with_capacity(2);
+    handles.push(<Tp<Exchange> as Transport<Exchange, Kind>>::distribute_messages(
+        Exchange::ID,
+        ws_sink,
+        ws_sink_rx,
+    ));
+
+    // Spawn optional task to distribute custom application-level pings to the exchange
+    if let Some(ping_interval) = Exchange::ping_interval() {
+        if let Some(handle) = <Tp<Exchange> as Transport<Exchange, Kind>>::schedule_pings(
             Exchange::ID,
-            ws_sink,
-            ws_sink_rx,
-        ));
+            ws_sink_tx.clone(),
+            ping_interval,
+        ) {
+            handles.push(handle);
+        }
+    }
+
+    // Construct Transformer associated with this Exchange and SubKind, handing it a clone of the
+    // shared ActivityTracker: the Transformer sits on the ExchangeStream decode path and touches the
+    // tracker for every inbound frame - including exchange pongs that yield no normalised Market
+    // event - so the ReconnectingStream watchdog counts a quiet-but-healthy connection as alive.
+    let activity = ActivityTracker::new();
+    let transformer = Transformer::new(ws_sink_tx, activity.clone(), map).await?;
+
+    Ok((
+        ExchangeWsStream::new(ws_stream, transformer),
+        TaskGuard::new(handles),
+        activity,
+    ))
+}
 
-        // Spawn optional task to distribute custom application-level pings to the exchange
-        if let Some(ping_interval) = Exchange::ping_interval() {
-            tokio::spawn(schedule_pings_to_exchange(
-                Exchange::ID,
-                ws_sink_tx.clone(),
-                ping_interval,
-            ));
+/// Jittered exponential backoff policy used by [`ReconnectingStream`] to space out reconnect
+/// attempts after the inner [`MarketStream`] terminates.
+///
+/// Uses the "full jitter" strategy: the delay for a given attempt is drawn uniformly from
+/// `[0, min(base * 2^attempt, cap)]`, which spreads reconnect storms when many streams flap at
+/// once while still bounding the worst-case wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectBackoff {
+    /// Starting delay for the first reconnect attempt.
+    pub base: Duration,
+    /// Upper bound on the (pre-jitter) delay.
+    pub cap: Duration,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(500),
+            cap: Duration::from_secs(30),
         }
+    }
+}
 
-        // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+impl ReconnectBackoff {
+    /// Full-jitter delay for the provided zero-indexed reconnect `attempt`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exp = self.base.saturating_mul(2u32.saturating_pow(attempt.min(31)));
+        let ceiling = std::cmp::min(exp, self.cap).as_millis() as u64;
+        let millis = rand::thread_rng().gen_range(0..=ceiling);
+        Duration::from_millis(millis)
+    }
+}
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+/// [`Stream`] adaptor that keeps an inner [`ExchangeWsStream`] alive across disconnects by
+/// transparently reconnecting and replaying the original [`Subscription`]s.
+///
+/// When the inner stream yields `None` or a disconnect-flavoured [`DataError`],
+/// [`ReconnectingStream`] drives a reconnect loop governed by [`ReconnectBackoff`], re-running the
+/// same connect & subscribe step as [`MarketStream::init`] to rebuild the subscription
+/// [`Map`](subscriber::Map) and respawn the `distribute_messages_to_exchange` /
+/// `schedule_pings_to_exchange` tasks. The previous inner stream and its [`TaskGuard`] are dropped
+/// before the new one is built, so the guard aborts its forwarding tasks and no duplicate
+/// subscription tasks leak on rapid flapping.
+///
+/// Reconnection is surfaced to the consumer as [`DataError::Reconnecting`] only when
+/// [`emit_reconnecting`](Self::with_reconnecting_events) is enabled; existing consumers that want
+/// hard failure on disconnect can leave it disabled and treat termination as terminal.
+pub struct ReconnectingStream<Exchange, Kind, Transformer>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    backoff: ReconnectBackoff,
+    emit_reconnecting: bool,
+    attempt: u32,
+    activity: ActivityTracker,
+    health: Option<HealthConfig>,
+    health_interval: Option<tokio::time::Interval>,
+    state: ReconnectState<Transformer, Kind::Event>,
+}
+
+/// Internal state machine for [`ReconnectingStream`]: either polling a live inner stream, or
+/// awaiting the next reconnect (optionally yielding a pending [`DataError`] event first).
+#[allow(clippy::type_complexity)]
+enum ReconnectState<Transformer, Event> {
+    Active(ExchangeWsStream<Transformer>, TaskGuard),
+    PendingEvent(
+        DataError,
+        BoxFuture<
+            'static,
+            Result<(ExchangeWsStream<Transformer>, TaskGuard, ActivityTracker), DataError>,
+        >,
+    ),
+    Reconnecting(
+        BoxFuture<
+            'static,
+            Result<(ExchangeWsStream<Transformer>, TaskGuard, ActivityTracker), DataError>,
+        >,
+    ),
+    // `Event` only appears behind the associated `Stream::Item`; retained for variance clarity.
+    _Phantom(std::marker::PhantomData<Event>),
+}
+
+impl<Exchange, Kind, Transformer> std::fmt::Debug for ReconnectingStream<Exchange, Kind, Transformer>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingStream")
+            .field("exchange", &Exchange::ID)
+            .field("subscriptions", &self.subscriptions.len())
+            .field("backoff", &self.backoff)
+            .field("emit_reconnecting", &self.emit_reconnecting)
+            .field("attempt", &self.attempt)
+            .finish()
+    }
+}
+
+impl<Exchange, Kind, Transformer> ReconnectingStream<Exchange, Kind, Transformer>
+where
+    Exchange: Connector + Send + Sync + 'static,
+    Exchange::Transport: Transport<
+        Exchange,
+        Kind,
+        Sink = WsSink,
+        Stream = WsStream,
+        Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap,
+    >,
+    Kind: SubKind + Send + Sync + 'static,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Clone,
+{
+    /// Connect and subscribe once, retaining a clone of the `subscriptions` so they can be replayed
+    /// on every subsequent reconnect, plus a [`TaskGuard`] that aborts this connection's tasks when
+    /// the connection is torn down.
+    pub async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError> {
+        let (inner, guard, activity) =
+            connect_and_spawn::<Exchange, Kind, Transformer>(subscriptions).await?;
+
+        // Default the watchdog to `3 ×` the exchange's ping cadence (see [`HealthConfig::from_interval`])
+        // so a half-open connection is detected without a hand-assembled [`HealthConfig`]. Exchanges
+        // with no application-level ping have no cadence to key off, so the watchdog stays disabled.
+        let health = Exchange::ping_interval()
+            .map(|ping_interval| HealthConfig::from_interval(&ping_interval.interval));
+        let health_interval = health.map(|health| tokio::time::interval(health.staleness_timeout));
+
+        Ok(Self {
+            subscriptions: subscriptions.to_vec(),
+            backoff: ReconnectBackoff::default(),
+            emit_reconnecting: false,
+            attempt: 0,
+            activity,
+            health,
+            health_interval,
+            state: ReconnectState::Active(inner, guard),
+        })
+    }
+
+    /// Override the default [`ReconnectBackoff`] policy.
+    pub fn with_backoff(mut self, backoff: ReconnectBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Opt in to surfacing [`DataError::Reconnecting`] / [`DataError::ConnectionStale`] events to
+    /// the consumer on each reconnect.
+    pub fn with_reconnecting_events(mut self, emit: bool) -> Self {
+        self.emit_reconnecting = emit;
+        self
+    }
+
+    /// Enable the connection watchdog: if no inbound activity (a yielded item, or a frame the
+    /// Transformer records on the [`ActivityTracker`]) is observed within
+    /// [`HealthConfig::staleness_timeout`], the connection is declared stale and torn down so the
+    /// reconnect loop rebuilds it. Must be called from within a Tokio runtime.
+    pub fn with_health(mut self, health: HealthConfig) -> Self {
+        self.health_interval = Some(tokio::time::interval(health.staleness_timeout));
+        self.health = Some(health);
+        self.activity.touch();
+        self
+    }
+
+    /// Build the future that reconnects & resubscribes for the retained subscriptions, yielding the
+    /// new stream and its [`TaskGuard`].
+    #[allow(clippy::type_complexity)]
+    fn reconnect_future(
+        &self,
+    ) -> BoxFuture<
+        'static,
+        Result<(ExchangeWsStream<Transformer>, TaskGuard, ActivityTracker), DataError>,
+    > {
+        let subscriptions = self.subscriptions.clone();
+        async move { connect_and_spawn::<Exchange, Kind, Transformer>(&subscriptions).await }.boxed()
     }
 }
 
-/// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] to the exchange via
-/// the [`WsSink`].
+impl<Exchange, Kind, Transformer> Stream for ReconnectingStream<Exchange, Kind, Transformer>
+where
+    Exchange: Connector + Send + Sync + 'static,
+    Exchange::Transport: Transport<
+        Exchange,
+        Kind,
+        Sink = WsSink,
+        Stream = WsStream,
+        Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap,
+    >,
+    Kind: SubKind + Send + Sync + 'static,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send + Unpin + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Clone,
+{
+    type Item = Result<Market<Kind::Event>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Watchdog: while connected, tear down a silently stalled connection so the reconnect
+            // loop can rebuild it. Polling the interval registers a timer, so the task still wakes
+            // to run this check even when the inner stream has gone quiet.
+            if matches!(self.state, ReconnectState::Active(..)) {
+                if let (Some(health), Some(interval)) =
+                    (self.health, self.health_interval.as_mut())
+                {
+                    let mut ticked = false;
+                    while interval.poll_tick(cx).is_ready() {
+                        ticked = true;
+                    }
+                    if ticked && self.activity.since_activity() > health.staleness_timeout {
+                        let exchange = Exchange::ID;
+                        warn!(%exchange, "no inbound activity within staleness_timeout, reconnecting");
+                        self.begin_reconnect(DataError::ConnectionStale { exchange });
+                        continue;
+                    }
+                }
+            }
+
+            match &mut self.state {
+                ReconnectState::Active(inner, _guard) => match inner.poll_next_unpin(cx) {
+                    // Healthy item - reset the attempt counter, record inbound activity, yield
+                    Poll::Ready(Some(Ok(event))) => {
+                        self.attempt = 0;
+                        self.activity.touch();
+                        return Poll::Ready(Some(Ok(event)));
+                    }
+                    // Disconnect-flavoured error, or clean termination: begin the reconnect loop
+                    Poll::Ready(Some(Err(error))) if error.is_terminal() => {
+                        let exchange = Exchange::ID;
+                        warn!(%exchange, %error, "inner MarketStream disconnected, reconnecting");
+                        self.begin_reconnect(DataError::Reconnecting {
+                            exchange,
+                            attempt: self.attempt,
+                        });
+                    }
+                    Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                    Poll::Ready(None) => {
+                        let exchange = Exchange::ID;
+                        warn!(%exchange, "inner MarketStream ended, reconnecting");
+                        self.begin_reconnect(DataError::Reconnecting {
+                            exchange,
+                            attempt: self.attempt,
+                        });
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                ReconnectState::PendingEvent(..) => {
+                    // Emit the opt-in event, then fall through to await the reconnect
+                    if let ReconnectState::PendingEvent(error, future) = std::mem::replace(
+                        &mut self.state,
+                        ReconnectState::_Phantom(std::marker::PhantomData),
+                    ) {
+                        self.state = ReconnectState::Reconnecting(future);
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                    unreachable!("matched PendingEvent")
+                }
+                ReconnectState::Reconnecting(future) => match future.poll_unpin(cx) {
+                    Poll::Ready(Ok((inner, guard, activity))) => {
+                        debug!(exchange = %Exchange::ID, attempt = self.attempt, "reconnected MarketStream");
+                        // Adopt the new connection's tracker (touched by its Transformer) and reset the
+                        // activity clock so the watchdog doesn't immediately re-fire
+                        self.activity = activity;
+                        self.activity.touch();
+                        if let Some(interval) = self.health_interval.as_mut() {
+                            interval.reset();
+                        }
+                        self.state = ReconnectState::Active(inner, guard);
+                    }
+                    Poll::Ready(Err(error)) => {
+                        error!(exchange = %Exchange::ID, attempt = self.attempt, %error, "reconnect attempt failed");
+                        self.begin_reconnect(DataError::Reconnecting {
+                            exchange: Exchange::ID,
+                            attempt: self.attempt,
+                        });
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                ReconnectState::_Phantom(_) => unreachable!("transient reconnect state"),
+            }
+        }
+    }
+}
+
+impl<Exchange, Kind, Transformer> ReconnectingStream<Exchange, Kind, Transformer>
+where
+    Exchange: Connector + Send + Sync + 'static,
+    Exchange::Transport: Transport<
+        Exchange,
+        Kind,
+        Sink = WsSink,
+        Stream = WsStream,
+        Map = <Exchange::Subscriber as Subscriber<Exchange, Kind>>::SubscriptionMap,
+    >,
+    Kind: SubKind + Send + Sync + 'static,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Clone,
+{
+    /// Schedule the next reconnect after the backoff delay, dropping the stale inner stream so its
+    /// forwarding tasks tear down before the replacement tasks are spawned. `event` is the
+    /// [`DataError`] surfaced to the consumer first when reconnecting events are enabled.
+    fn begin_reconnect(&mut self, event: DataError) {
+        let delay = self.backoff.delay(self.attempt);
+        self.attempt = self.attempt.saturating_add(1);
+
+        let reconnect = self.reconnect_future();
+        let future = async move {
+            tokio::time::sleep(delay).await;
+            reconnect.await
+        }
+        .boxed();
+
+        self.state = if self.emit_reconnecting {
+            ReconnectState::PendingEvent(event, future)
+        } else {
+            ReconnectState::Reconnecting(future)
+        };
+    }
+}
+
+/// Async sink abstraction for the outbound (exchange-bound) side of a [`MarketStream`].
+///
+/// Decouples the [`ExchangeTransformer`]'s custom pong/subscription output from the concrete
+/// [`WsMessage`]/[`WsSink`] type so the same transformer logic can drive a FIX or HTTP backend, and
+/// so tests can inject a mock sink that records outbound payloads instead of opening a real socket.
+#[async_trait]
+pub trait Transmit<M>
+where
+    M: Send,
+{
+    /// Send a single outbound message to the exchange.
+    ///
+    /// A returned [`DataError`] for which [`DataError::is_terminal`] holds signals the forwarding
+    /// task to stop (eg/ the socket disconnected); any other error is logged and forwarding
+    /// continues.
+    async fn transmit(&mut self, msg: M) -> Result<(), DataError>;
+}
+
+#[async_trait]
+impl Transmit<WsMessage> for WsSink {
+    async fn transmit(&mut self, msg: WsMessage) -> Result<(), DataError> {
+        self.send(msg).await.map_err(DataError::from)
+    }
+}
+
+/// Backpressure policy for the transformer → exchange forwarding channel.
+///
+/// Previously baked in as an `unbounded_channel`; exposing it via [`Connector::backpressure`] lets
+/// each exchange trade memory growth under a slow sink against a bounded queue. Because the
+/// [`ExchangeTransformer`] sends from a synchronous context, a full bounded queue cannot apply
+/// await-backpressure; [`Bounded`](Self::Bounded) instead drops the overflowing payload (reported
+/// via [`OutboundError::Full`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Never drop; the queue grows unbounded (the historical default).
+    Unbounded,
+    /// Bounded queue of the given capacity; overflowing payloads are dropped. A capacity of `0` is
+    /// clamped to `1` (see [`channel`](Self::channel)) since a zero-capacity channel cannot hold the
+    /// non-blocking [`try_send`](tokio::sync::mpsc::Sender::try_send) used by [`OutboundTx::send`].
+    Bounded(usize),
+}
+
+impl Default for Backpressure {
+    fn default() -> Self {
+        Self::Unbounded
+    }
+}
+
+impl Backpressure {
+    /// Build the transformer → forwarding-task channel according to this policy.
+    ///
+    /// A [`Bounded(0)`](Self::Bounded) config is clamped to a capacity of `1` rather than panicking:
+    /// `tokio::sync::mpsc::channel(0)` panics (`buffer > 0`), and a per-exchange
+    /// [`Connector::backpressure`] returning `Bounded(0)` is valid-looking config, not a bug to abort on.
+    pub fn channel<M>(self) -> (OutboundTx<M>, OutboundRx<M>) {
+        match self {
+            Backpressure::Unbounded => {
+                let (tx, rx) = mpsc::unbounded_channel();
+                (OutboundTx::Unbounded(tx), OutboundRx::Unbounded(rx))
+            }
+            Backpressure::Bounded(capacity) => {
+                let (tx, rx) = mpsc::channel(capacity.max(1));
+                (OutboundTx::Bounded(tx), OutboundRx::Bounded(rx))
+            }
+        }
+    }
+}
+
+/// Sending half of a [`Backpressure`]-selected forwarding channel handed to the
+/// [`ExchangeTransformer`] (and ping task). Decouples the transformer from the concrete channel
+/// type so the policy is configurable rather than a baked-in `unbounded_channel`.
+#[derive(Clone, Debug)]
+pub enum OutboundTx<M> {
+    Unbounded(mpsc::UnboundedSender<M>),
+    Bounded(mpsc::Sender<M>),
+}
+
+/// Error returned by [`OutboundTx::send`] when a payload could not be queued.
+#[derive(Debug)]
+pub enum OutboundError<M> {
+    /// The receiving forwarding task has been dropped.
+    Closed(M),
+    /// The bounded queue was full and the payload was dropped.
+    Full(M),
+}
+
+impl<M> OutboundTx<M> {
+    /// Queue a message for the forwarding task. Non-blocking: a full [`Bounded`](Backpressure::Bounded)
+    /// queue drops the payload and returns [`OutboundError::Full`].
+    pub fn send(&self, msg: M) -> Result<(), OutboundError<M>> {
+        match self {
+            OutboundTx::Unbounded(tx) => tx.send(msg).map_err(|e| OutboundError::Closed(e.0)),
+            OutboundTx::Bounded(tx) => tx.try_send(msg).map_err(|e| match e {
+                mpsc::error::TrySendError::Full(m) => OutboundError::Full(m),
+                mpsc::error::TrySendError::Closed(m) => OutboundError::Closed(m),
+            }),
+        }
+    }
+}
+
+/// Receiving half of a [`Backpressure`]-selected forwarding channel, drained by
+/// [`distribute_messages_to_exchange`].
+#[derive(Debug)]
+pub enum OutboundRx<M> {
+    Unbounded(mpsc::UnboundedReceiver<M>),
+    Bounded(mpsc::Receiver<M>),
+}
+
+impl<M> OutboundRx<M> {
+    /// Await the next queued message, or `None` once all senders have dropped.
+    pub async fn recv(&mut self) -> Option<M> {
+        match self {
+            OutboundRx::Unbounded(rx) => rx.recv().await,
+            OutboundRx::Bounded(rx) => rx.recv().await,
+        }
+    }
+}
+
+/// Forward messages sent from the [`ExchangeTransformer`] to the exchange via the provided
+/// [`Transmit`] sink.
 ///
 /// **Note:**
 /// ExchangeTransformer is operating in a synchronous trait context so we use this separate task
-/// to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations.
-pub async fn distribute_messages_to_exchange(
+/// to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations. Routing through
+/// [`Transmit`] keeps the spawned-task forwarding model while decoupling it from the WebSocket
+/// message type.
+pub async fn distribute_messages_to_exchange<Sink, M>(
     exchange: ExchangeId,
-    mut ws_sink: WsSink,
-    mut ws_sink_rx: mpsc::UnboundedReceiver<WsMessage>,
-) {
-    while let Some(message) = ws_sink_rx.recv().await {
-        if let Err(error) = ws_sink.send(message).await {
-            if barter_integration::protocol::websocket::is_websocket_disconnected(&error) {
+    mut sink: Sink,
+    mut sink_rx: OutboundRx<M>,
+) where
+    Sink: Transmit<M> + Send,
+    M: Send,
+{
+    while let Some(message) = sink_rx.recv().await {
+        if let Err(error) = sink.transmit(message).await {
+            // Stop forwarding once the sink is disconnected; otherwise log and keep going
+            if error.is_terminal() {
                 break;
             }
 
-            // Log error only if WsMessage failed to send over a connected WebSocket
             error!(
                 %exchange,
                 %error,
-                "failed to send  output message to the exchange via WsSink"
+                "failed to send output message to the exchange via Transmit sink"
             );
         }
     }
 }
 
+/// Shared record of the last time an inbound exchange message (or pong) was observed.
+///
+/// A clone is handed to the [`ExchangeTransformer`] on the [`ExchangeStream`] decode path, which
+/// calls [`touch`](Self::touch) for every inbound frame - including exchange pongs that decode to no
+/// normalised [`Market`] event. The [`ReconnectingStream`] watchdog reads
+/// [`since_activity`](Self::since_activity) against the same shared timestamp to detect a half-open
+/// connection where the TCP socket is still "up" but the exchange has silently stopped sending data.
+#[derive(Clone, Debug)]
+pub struct ActivityTracker {
+    started: Arc<Instant>,
+    last_ms: Arc<AtomicU64>,
+}
+
+impl ActivityTracker {
+    /// Construct a tracker whose last activity is set to "now".
+    pub fn new() -> Self {
+        let started = Arc::new(Instant::now());
+        let last_ms = Arc::new(AtomicU64::new(started.elapsed().as_millis() as u64));
+        Self { started, last_ms }
+    }
+
+    /// Record that inbound activity has just been observed.
+    pub fn touch(&self) {
+        self.last_ms
+            .store(self.started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// [`Duration`] elapsed since the last observed inbound activity.
+    pub fn since_activity(&self) -> Duration {
+        let last = Duration::from_millis(self.last_ms.load(Ordering::Relaxed));
+        self.started.elapsed().saturating_sub(last)
+    }
+}
+
+impl Default for ActivityTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Connection health tuning for the [`ReconnectingStream`] watchdog.
+///
+/// If no inbound message or pong arrives within [`staleness_timeout`](Self::staleness_timeout),
+/// the connection is treated as stale and torn down so the reconnection layer can rebuild it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HealthConfig {
+    /// Maximum tolerated gap between inbound messages before the connection is declared stale.
+    pub staleness_timeout: Duration,
+}
+
+impl HealthConfig {
+    /// Staleness tolerance of `3 ×` the given [`PingInterval`] period, for per-exchange tuning.
+    pub fn from_interval(interval: &tokio::time::Interval) -> Self {
+        Self {
+            staleness_timeout: interval.period() * 3,
+        }
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            staleness_timeout: Duration::from_secs(15),
+        }
+    }
+}
+
 /// Schedule the sending of custom application-level ping [`WsMessage`]s to the exchange using
 /// the provided [`PingInterval`].
 ///
@@ -168,7 +877,7 @@ pub async fn distribute_messages_to_exchange(
 ///  - This is additional to the protocol-level pings already handled by `tokio_tungstenite`.
 pub async fn schedule_pings_to_exchange(
     exchange: ExchangeId,
-    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    ws_sink_tx: OutboundTx<WsMessage>,
     PingInterval { mut interval, ping }: PingInterval,
 ) {
     loop {
@@ -179,8 +888,101 @@ pub async fn schedule_pings_to_exchange(
         let payload = ping();
         debug!(%exchange, %payload, "sending custom application-level ping to exchange");
 
-        if ws_sink_tx.send(payload).is_err() {
-            break;
+        // A dropped receiver means the forwarding task is gone, so stop pinging; a full bounded
+        // queue just drops this one ping (the next tick retries) rather than tearing everything down.
+        match ws_sink_tx.send(payload) {
+            Ok(()) => {}
+            Err(OutboundError::Full(_)) => {
+                warn!(%exchange, "dropping custom application-level ping; outbound queue is full");
+            }
+            Err(OutboundError::Closed(_)) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::error::SocketError;
+    use std::sync::Mutex;
+
+    /// Recording [`Transmit`] sink used to drive [`distribute_messages_to_exchange`] without a socket.
+    #[derive(Debug)]
+    struct MockSink {
+        sent: Arc<Mutex<Vec<WsMessage>>>,
+        // Return a terminal [`DataError`] on the call with this (0-based) index, if any.
+        fail_on: Option<usize>,
+        calls: usize,
+    }
+
+    #[async_trait]
+    impl Transmit<WsMessage> for MockSink {
+        async fn transmit(&mut self, msg: WsMessage) -> Result<(), DataError> {
+            let index = self.calls;
+            self.calls += 1;
+            if self.fail_on == Some(index) {
+                return Err(DataError::from(SocketError::Sink));
+            }
+            self.sent.lock().unwrap().push(msg);
+            Ok(())
+        }
+    }
+
+    fn ping(i: usize) -> WsMessage {
+        WsMessage::Text(format!("ping-{i}").into())
+    }
+
+    #[tokio::test]
+    async fn distribute_forwards_every_message_then_stops_when_senders_drop() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sink = MockSink {
+            sent: Arc::clone(&sent),
+            fail_on: None,
+            calls: 0,
+        };
+
+        let (tx, rx) = Backpressure::Unbounded.channel::<WsMessage>();
+        for i in 0..3 {
+            tx.send(ping(i)).unwrap();
         }
+        drop(tx);
+
+        distribute_messages_to_exchange(ExchangeId::BinanceSpot, sink, rx).await;
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 3);
+        assert_eq!(sent[0], ping(0));
+        assert_eq!(sent[2], ping(2));
+    }
+
+    #[tokio::test]
+    async fn distribute_breaks_on_terminal_error() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sink = MockSink {
+            sent: Arc::clone(&sent),
+            fail_on: Some(1),
+            calls: 0,
+        };
+
+        let (tx, rx) = Backpressure::Unbounded.channel::<WsMessage>();
+        for i in 0..3 {
+            tx.send(ping(i)).unwrap();
+        }
+        drop(tx);
+
+        distribute_messages_to_exchange(ExchangeId::BinanceSpot, sink, rx).await;
+
+        // The terminal error on the second message tears the loop down, so the third never forwards.
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0], ping(0));
+    }
+
+    #[tokio::test]
+    async fn bounded_zero_capacity_is_clamped_not_panicking() {
+        // A Bounded(0) policy must not panic (mpsc::channel(0) would); it is clamped to capacity 1.
+        let (tx, mut rx) = Backpressure::Bounded(0).channel::<WsMessage>();
+        tx.send(ping(0)).unwrap();
+        assert_eq!(rx.recv().await, Some(ping(0)));
     }
 }
@@ -0,0 +1,189 @@
+//! Consolidated cross-exchange best-bid/offer (BBO) stream.
+//!
+//! [`ConsolidatedStream`] merges several per-exchange top-of-book [`MarketStream`](crate::MarketStream)s
+//! for a single logical instrument and emits a normalised [`Market<ConsolidatedQuote>`](Market)
+//! whenever the aggregate best bid or best ask changes. Each side is tagged with the originating
+//! [`ExchangeId`], giving an arbitrage / market-making consumer one authoritative price derived from
+//! many venues instead of `N` independent [`OrderBookL1`] streams to merge by hand.
+
+use crate::{
+    error::DataError,
+    event::Market,
+    exchange::ExchangeId,
+    subscription::book::OrderBookL1,
+};
+use futures::{
+    stream::{select_all, BoxStream, SelectAll},
+    Stream, StreamExt,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+/// One side (bid or ask) of a [`ConsolidatedQuote`], tagged with the venue it originated from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SideQuote {
+    pub exchange: ExchangeId,
+    pub price: f64,
+    pub amount: f64,
+}
+
+/// Aggregate best bid / best ask across several exchanges for a single logical instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConsolidatedQuote {
+    pub best_bid: Option<SideQuote>,
+    pub best_ask: Option<SideQuote>,
+}
+
+impl ConsolidatedQuote {
+    /// Mid price of the consolidated best bid & ask, if both sides are present.
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) => Some((bid.price + ask.price) / 2.0),
+            _ => None,
+        }
+    }
+}
+
+/// Synchronous access to the current consolidated rate, for consumers (eg/ an arbitrage bot) that
+/// need to poll the latest authoritative price rather than await the next [`Stream`] item.
+pub trait LatestQuote {
+    /// Snapshot of the current consolidated best bid & ask.
+    fn latest_quote(&self) -> ConsolidatedQuote;
+}
+
+/// Shared handle returned by [`ConsolidatedStream::quote_handle`] for synchronous polling.
+#[derive(Clone, Debug)]
+pub struct ConsolidatedQuoteHandle {
+    latest: Arc<Mutex<ConsolidatedQuote>>,
+}
+
+impl LatestQuote for ConsolidatedQuoteHandle {
+    fn latest_quote(&self) -> ConsolidatedQuote {
+        *self.latest.lock().expect("ConsolidatedQuote Mutex poisoned")
+    }
+}
+
+/// [`Stream`] yielding a normalised [`Market<ConsolidatedQuote>`](Market) whenever the aggregate top
+/// of book changes across the underlying per-exchange L1 streams.
+pub struct ConsolidatedStream {
+    inner: SelectAll<BoxStream<'static, Result<Market<OrderBookL1>, DataError>>>,
+    latest_per_exchange: HashMap<ExchangeId, OrderBookL1>,
+    emitted: ConsolidatedQuote,
+    shared: Arc<Mutex<ConsolidatedQuote>>,
+}
+
+impl fmt::Debug for ConsolidatedStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsolidatedStream")
+            .field("venues", &self.latest_per_exchange.len())
+            .field("emitted", &self.emitted)
+            .finish()
+    }
+}
+
+impl ConsolidatedStream {
+    /// Consolidate the provided per-exchange top-of-book [`MarketStream`](crate::MarketStream)s for
+    /// one logical instrument.
+    pub fn new(
+        streams: impl IntoIterator<
+            Item = BoxStream<'static, Result<Market<OrderBookL1>, DataError>>,
+        >,
+    ) -> Self {
+        Self {
+            inner: select_all(streams),
+            latest_per_exchange: HashMap::new(),
+            emitted: ConsolidatedQuote::default(),
+            shared: Arc::new(Mutex::new(ConsolidatedQuote::default())),
+        }
+    }
+
+    /// Obtain a [`LatestQuote`] handle that reflects the most recently emitted consolidated quote.
+    pub fn quote_handle(&self) -> ConsolidatedQuoteHandle {
+        ConsolidatedQuoteHandle {
+            latest: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Recompute the aggregate best bid (max price) and best ask (min price) across all venues.
+    ///
+    /// Ties are broken deterministically by [`ExchangeId`] so the emitted quote does not depend on
+    /// `HashMap` iteration order - otherwise two venues quoting the same price could flip the tagged
+    /// `exchange` between polls and trigger a spurious emit.
+    fn recompute(&self) -> ConsolidatedQuote {
+        let mut quote = ConsolidatedQuote::default();
+
+        for (exchange, book) in &self.latest_per_exchange {
+            let bid = SideQuote {
+                exchange: *exchange,
+                price: book.best_bid.price,
+                amount: book.best_bid.amount,
+            };
+            if quote.best_bid.map_or(true, |best| {
+                (bid.price, bid.exchange) > (best.price, best.exchange)
+            }) {
+                quote.best_bid = Some(bid);
+            }
+
+            let ask = SideQuote {
+                exchange: *exchange,
+                price: book.best_ask.price,
+                amount: book.best_ask.amount,
+            };
+            if quote.best_ask.map_or(true, |best| {
+                (ask.price, ask.exchange) < (best.price, best.exchange)
+            }) {
+                quote.best_ask = Some(ask);
+            }
+        }
+
+        quote
+    }
+}
+
+impl Stream for ConsolidatedStream {
+    type Item = Result<Market<ConsolidatedQuote>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.inner.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(market))) => {
+                    // Record this venue's latest top-of-book, then recompute the aggregate
+                    self.latest_per_exchange
+                        .insert(market.exchange, market.kind.clone());
+                    let quote = self.recompute();
+
+                    // Only yield when the consolidated top actually changed, to avoid spamming
+                    if quote == self.emitted {
+                        continue;
+                    }
+                    self.emitted = quote;
+                    *self.shared.lock().expect("ConsolidatedQuote Mutex poisoned") = quote;
+
+                    return Poll::Ready(Some(Ok(Market {
+                        exchange_time: market.exchange_time,
+                        received_time: market.received_time,
+                        exchange: market.exchange,
+                        instrument: market.instrument,
+                        kind: quote,
+                    })));
+                }
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Boxed helper so heterogeneous per-exchange streams can be collected into a [`ConsolidatedStream`].
+pub fn boxed_l1<S>(stream: S) -> BoxStream<'static, Result<Market<OrderBookL1>, DataError>>
+where
+    S: Stream<Item = Result<Market<OrderBookL1>, DataError>> + Send + 'static,
+{
+    Box::pin(stream) as Pin<Box<dyn Stream<Item = _> + Send>>
+}
@@ -0,0 +1,5 @@
+//! High-level API types used for building [`MarketStream`](crate::MarketStream)s from collections
+//! of Barter [`Subscription`](crate::subscription::Subscription)s.
+
+/// Consolidated cross-exchange best-bid/offer (BBO) stream.
+pub mod consolidated;